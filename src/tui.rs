@@ -7,11 +7,16 @@ use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::Color;
 use ratatui::style::Style;
-use ratatui::widgets::Widget;
+use ratatui::widgets::{Block, Widget};
 use ratatui::DefaultTerminal;
 
 use kronark_node_parser::kronarknode::{
-	instance::Instance, nodes::NodeEntry, roots::Roots, socket::DataType, types::TypeEntry, Node,
+	instance::Instance,
+	nodes::{NodeEntry, SocketDef},
+	roots::Roots,
+	socket::DataType,
+	types::TypeEntry,
+	Node,
 };
 
 // Take ownership of a `Node` and parse out its contents
@@ -24,9 +29,10 @@ use kronark_node_parser::kronarknode::{
 // TO the instance in question, plus one. If an instance has no connections on its input side, it
 // has a connection depth of zero. This means we'll be ignoring the stored x values of the instance
 //
-// The vertical placement of an instance is compressed with all instances in its column, with a
-// padding of one. The order of vertical placement will respect the y values stored in the
-// instances, but exact positioning will not
+// Vertical placement is no longer just a tight stack: `place_rows` below walks columns
+// leftmost-first and aligns each instance's row to the upstream socket it connects from, only
+// ever pushing later instances in a column further down to resolve overlaps. Plain stacking (a
+// padding of one, in stored-y order) is still what happens when there's nothing to align to.
 //
 // Padding between columns is based on how many incoming connections the right column has plus how
 // many outgoing connections the previous column has that do NOT link to the right column
@@ -41,37 +47,21 @@ use kronark_node_parser::kronarknode::{
 // plus an additional column of spacing for each output that needs to leave the region rather than
 // connecting to the right column
 //
-// When a connection bends back horizontally, it's possible that two lines may overlap if we
-// compress the vertical space as much as possible, as shown below:
-// ********************************
-// ───┐ ┌────
-//    │ │
-// ─────┘────
-// ********************************
-// In this situation, the line starting at the top left was drawn first, extended out to its target
-// column, then went back horizontal at the target row. The bottom line did the same and damaged
-// the drawing of the top line. I haven't thought up a good way to avoid this, so the simple method
-// which will get us close to a functional renderer as quickly as possible is to simply alternate
-// the socket positions from column to column, so that inputs and outputs never lie on the same
-// row. Additionally, we will have to detect intersections to replace them with the character '┼'
-// (or we ignore that because it's not that important and we can still make sense of it)
+// `routing` below draws the actual wires: a horizontal run out of the source, a bend in a
+// channel dedicated to that connection, then a horizontal run into the destination. Channels are
+// allocated greedily per column gap so two bends whose rows overlap never share an x (bends with
+// disjoint row ranges may reuse one), which is what the grown
+// `assign_columns_x` padding above is actually buying room for. Two segments that still end up on
+// the same cell (e.g. a passthrough connection crossing a bend) are merged into '┼' rather than
+// one clobbering the other.
 //
 // I am *very* open to ideas for this. Remember, we're not trying to make it pretty, just good
 // enough so we can document the nodes. Pretty comes later.
 //
-// Additionally, out of necessity I believe it's a good idea to allow scrolling of the view window
-// with arrow keys, to browse larger node graphs. `ratatui` does not inherently support having its
-// widgets overdraw, but we can implement our own widgets and draw to the buffer provided,
-// performing our own overdraw culling. See the video and main.rs file sent in the Kronark Discord
-// under the forum thread for this project. I apologize in advance for the shitty code in that
-// file, it was put together as hastily as I could to get a demonstration.
-// Alternatively, an idea I had while writing this, we could instead only scroll by column and not
-// worry about culling overdraw. We generate a simple widget for each instance, do some simple
-// calculations to determine the column widths, then render only as many columns would 100% fit on
-// screen. Pressing right arrow would shift the leftmost visible column over once. Lines connecting
-// to offscreen instances will draw as much of their route as they can, then terminate in an angle
-// bracket indicating they go offscreen. This might be simpler. Same logic can be applied to
-// vertical scrolling, instead you go by instance within a column.
+// Scrolling is handled below via `OverdrawBuffer`: widgets draw in the graph's global layout
+// coordinates and `OverdrawBuffer` translates by `(x_shift, y_shift)` into the terminal's actual
+// buffer, silently dropping anything that lands outside the visible area. This means layout code
+// never has to think about what's currently on screen, only about where things live in the graph.
 //
 // I've tried to outline what the structure of this renderer could look like below, but this is
 // certainly not final. If someone begins to implement this or components of this, do let me know
@@ -79,7 +69,74 @@ use kronark_node_parser::kronarknode::{
 
 // Buffer intermediary that will ignore draws entirely offscreen and handle discarding of draws
 // partially offscreen
-struct OverdrawBuffer {}
+//
+// Everything drawn through here is addressed in *global* layout coordinates, i.e. the same
+// coordinates instances are positioned at in `NodeDefRenderer`. `(x_shift, y_shift)` is subtracted
+// to land in the wrapped `Buffer`'s local space, then clipped against `visible`.
+struct OverdrawBuffer<'a> {
+	buf: &'a mut Buffer,
+	visible: Rect,
+	x_shift: i32,
+	y_shift: i32,
+}
+impl<'a> OverdrawBuffer<'a> {
+	fn new(buf: &'a mut Buffer, visible: Rect, x_shift: i32, y_shift: i32) -> Self {
+		OverdrawBuffer {
+			buf,
+			visible,
+			x_shift,
+			y_shift,
+		}
+	}
+
+	// Translates a global coordinate into the wrapped buffer's local space, returning `None` if
+	// the result falls outside `visible`
+	fn to_local(&self, global_x: i32, global_y: i32) -> Option<(u16, u16)> {
+		let local_x = global_x - self.x_shift;
+		let local_y = global_y - self.y_shift;
+
+		if local_x < self.visible.x as i32
+			|| local_y < self.visible.y as i32
+			|| local_x >= (self.visible.x + self.visible.width) as i32
+			|| local_y >= (self.visible.y + self.visible.height) as i32
+		{
+			return None;
+		}
+
+		Some((local_x as u16, local_y as u16))
+	}
+
+	fn set_cell(&mut self, global_x: i32, global_y: i32, symbol: &str, style: Style) {
+		let Some((local_x, local_y)) = self.to_local(global_x, global_y) else {
+			return;
+		};
+
+		let cell = &mut self.buf[(local_x, local_y)];
+		cell.set_symbol(symbol);
+		cell.set_style(style);
+	}
+
+	// Writes up to `max_width` characters starting at the given global position. Each character
+	// is clipped individually, so a string that starts offscreen and scrolls into view still
+	// draws its visible tail instead of being skipped outright
+	fn set_stringn(&mut self, x: i32, y: i32, string: &str, max_width: usize, style: Style) {
+		let mut char_buf = [0u8; 4];
+		for (i, ch) in string.chars().take(max_width).enumerate() {
+			self.set_cell(x + i as i32, y, ch.encode_utf8(&mut char_buf), style);
+		}
+	}
+
+	fn set_style(&mut self, x: i32, y: i32, width: u16, height: u16, style: Style) {
+		for row in 0..height as i32 {
+			for col in 0..width as i32 {
+				let Some((local_x, local_y)) = self.to_local(x + col, y + row) else {
+					continue;
+				};
+				self.buf[(local_x, local_y)].set_style(style);
+			}
+		}
+	}
+}
 
 struct Size {
 	width: i32,
@@ -89,10 +146,48 @@ trait WidgetSize {
 	fn get_size_estimate(&self) -> Size;
 }
 // Uses `OverdrawBuffer` instead of `Buffer` and takes a shift value
-// Should auto-implement on `Widget`s
+// Auto-implemented for any `Widget` below
 trait OverdrawWidget {
 	fn render(&self, area: Rect, x_shift: i32, y_shift: i32, buf: &mut OverdrawBuffer);
 }
+// Renders the widget into an on-stack `Buffer` sized to its own area using the ordinary `Widget`
+// impl, then blits the result through `OverdrawBuffer`'s clipped writer. This means any existing
+// `Widget` gets overdraw culling for free without having to be scroll-aware itself.
+impl<W> OverdrawWidget for W
+where
+	W: Widget + Copy,
+{
+	fn render(&self, area: Rect, x_shift: i32, y_shift: i32, buf: &mut OverdrawBuffer) {
+		let local_x = area.x as i32 - x_shift;
+		let local_y = area.y as i32 - y_shift;
+		let visible = buf.visible;
+
+		// Skip the (potentially expensive) render entirely if none of it could be visible
+		if local_x + area.width as i32 <= visible.x as i32
+			|| local_y + area.height as i32 <= visible.y as i32
+			|| local_x >= (visible.x + visible.width) as i32
+			|| local_y >= (visible.y + visible.height) as i32
+		{
+			return;
+		}
+
+		let local_area = Rect::new(0, 0, area.width, area.height);
+		let mut local_buf = Buffer::empty(local_area);
+		(*self).render(local_area, &mut local_buf);
+
+		for row in 0..area.height {
+			for col in 0..area.width {
+				let cell = &local_buf[(col, row)];
+				buf.set_cell(
+					area.x as i32 + col as i32,
+					area.y as i32 + row as i32,
+					cell.symbol(),
+					cell.style(),
+				);
+			}
+		}
+	}
+}
 
 // Thin wrapper for type-safety
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -104,12 +199,19 @@ impl Deref for InstanceID {
 	}
 }
 
-// TODO: We need a way to store the padding. Should it be here or elsewhere?
 #[derive(Debug, Default)]
 struct Column {
-	instances: Vec<InstanceID>,
+	instances: Vec<InstanceRenderer>,
 }
 
+// Gap, in cells, left between the widest input label and the widest output label inside a box
+const LABEL_GAP: i32 = 2;
+// Horizontal/vertical gaps between an instance box and its neighbors. `PAD_X` only still applies
+// to the input root's gap and the gap right of the depth-0 column - every other gap is sized from
+// the connections routed through it, with a floor of one cell
+const PAD_X: i32 = 3;
+const PAD_Y: i32 = 1;
+
 #[derive(Debug)]
 struct InstanceRenderer {
 	id: InstanceID,
@@ -117,18 +219,56 @@ struct InstanceRenderer {
 	y_pos: i32,
 	width: i32,
 	height: i32,
+	name: String,
+	input_labels: Vec<String>,
+	output_labels: Vec<String>,
 }
 impl InstanceRenderer {
-	fn from_instance(instance: &Instance, x_pos: i32, y_pos: i32) -> InstanceRenderer {
-		// TODO: Replace dummy values with correctly computed values
+	fn from_instance(
+		instance: &Instance,
+		node_table: &[NodeEntry],
+		type_table: &[TypeEntry],
+		x_pos: i32,
+		y_pos: i32,
+	) -> InstanceRenderer {
+		let node = &node_table[instance.node as usize];
+		let label_of = |socket: &SocketDef| type_table[socket.type_id as usize].name.clone();
+		let input_labels: Vec<String> = node.inputs.iter().map(label_of).collect();
+		let output_labels: Vec<String> = node.outputs.iter().map(label_of).collect();
+
+		let name_len = node.name.chars().count() as i32;
+		let widest_input = input_labels.iter().map(|l| l.chars().count() as i32).max().unwrap_or(0);
+		let widest_output = output_labels.iter().map(|l| l.chars().count() as i32).max().unwrap_or(0);
+		let width = name_len.max(widest_input + LABEL_GAP + widest_output) + 2; // + left/right border
+		let height = 1 + input_labels.len().max(output_labels.len()).max(1) as i32 + 1; // header + body + bottom border
+
 		InstanceRenderer {
 			id: InstanceID(instance.key),
 			x_pos,
 			y_pos,
-			width: 20, // TEMP
-			height: 5, // TEMP
+			width,
+			height,
+			name: node.name.clone(),
+			input_labels,
+			output_labels,
 		}
 	}
+
+	// Row a socket sits on, relative to the box's top-left - both label columns start directly
+	// under the header border, so input and output sockets sharing an index share a row
+	fn socket_row(&self, index: usize) -> i32 {
+		1 + index as i32
+	}
+
+	// Global-coordinate anchor the router should aim an incoming connection at
+	fn input_anchor(&self, index: usize) -> (i32, i32) {
+		(self.x_pos, self.y_pos + self.socket_row(index))
+	}
+
+	// Global-coordinate anchor the router should exit an outgoing connection from
+	fn output_anchor(&self, index: usize) -> (i32, i32) {
+		(self.x_pos + self.width - 1, self.y_pos + self.socket_row(index))
+	}
 }
 impl WidgetSize for InstanceRenderer {
 	fn get_size_estimate(&self) -> Size {
@@ -138,10 +278,159 @@ impl WidgetSize for InstanceRenderer {
 		}
 	}
 }
-impl Widget for &InstanceRenderer {
+// Thin `Widget` wrapper around `&InstanceRenderer` that also knows whether it's the selected
+// instance, so it can draw its border differently - `InstanceRenderer` itself stays unaware of
+// selection, which lives on `NodeDefRenderer`
+#[derive(Clone, Copy)]
+struct InstanceBox<'a> {
+	renderer: &'a InstanceRenderer,
+	selected: bool,
+}
+impl<'a> Widget for InstanceBox<'a> {
 	fn render(self, area: Rect, buf: &mut Buffer) {
-		// TODO: Make this render the node properly
-		buf.set_style(area, Style::new().bg(Color::Rgb(30, 30, 30)));
+		let border_style = if self.selected {
+			Style::new().fg(Color::Yellow)
+		} else {
+			Style::new()
+		};
+		Block::bordered()
+			.border_style(border_style)
+			.title(self.renderer.name.as_str())
+			.render(area, buf);
+
+		for (i, label) in self.renderer.input_labels.iter().enumerate() {
+			let row = area.y + self.renderer.socket_row(i) as u16;
+			buf.set_stringn(area.x + 1, row, label, area.width as usize - 2, Style::new());
+		}
+		for (i, label) in self.renderer.output_labels.iter().enumerate() {
+			let row = area.y + self.renderer.socket_row(i) as u16;
+			let label_width = label.chars().count() as u16;
+			buf.set_stringn(
+				area.x + area.width - 1 - label_width,
+				row,
+				label,
+				label_width as usize,
+				Style::new(),
+			);
+		}
+	}
+}
+
+// Alphabet jump labels are drawn from, home row first so the common case (few instances, one
+// keystroke) lands on the easiest keys to reach
+const JUMP_ALPHABET: &str = "asdfghjklqwertyuiopzxcvbnm";
+
+// Base-`alphabet.len()` labels for `count` items, in assignment order: single characters as long
+// as they all fit in `alphabet`, falling back to as many characters as it takes (most significant,
+// slowest-cycling, digit first) once `count` exceeds `alphabet.len()` - so this never runs out of
+// labels no matter how large the graph is. Pulled out of `assign_jump_labels` so the digit-count
+// and base-N math can be unit tested without needing a full `NodeDefRenderer`.
+fn jump_labels(alphabet: &[char], count: usize) -> Vec<String> {
+	let base = alphabet.len();
+	let mut digits = 1;
+	while base.pow(digits as u32) < count {
+		digits += 1;
+	}
+
+	(0..count)
+		.map(|i| {
+			let mut chars: Vec<char> = (0..digits)
+				.scan(i, |n, _| {
+					let digit = alphabet[*n % base];
+					*n /= base;
+					Some(digit)
+				})
+				.collect();
+			chars.reverse();
+			chars.into_iter().collect()
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod jump_label_tests {
+	use super::jump_labels;
+
+	fn alphabet(n: usize) -> Vec<char> {
+		('a'..).take(n).collect()
+	}
+
+	#[test]
+	fn single_character_labels_while_within_the_alphabet() {
+		let labels = jump_labels(&alphabet(3), 3);
+		assert_eq!(labels, vec!["a", "b", "c"]);
+	}
+
+	#[test]
+	fn switches_to_two_characters_right_after_the_alphabet_is_exhausted() {
+		let alphabet = alphabet(2);
+		// exactly alphabet.len() items still fit in one character
+		let labels = jump_labels(&alphabet, 2);
+		assert_eq!(labels, vec!["a", "b"]);
+
+		// one more than alphabet.len() forces two-character labels for every item
+		let labels = jump_labels(&alphabet, 3);
+		assert_eq!(labels, vec!["aa", "ab", "ba"]);
+	}
+
+	#[test]
+	fn switches_to_three_characters_right_after_two_characters_are_exhausted() {
+		let alphabet = alphabet(2);
+		// exactly alphabet.len()^2 items still fit in two characters
+		let labels = jump_labels(&alphabet, 4);
+		assert_eq!(labels, vec!["aa", "ab", "ba", "bb"]);
+
+		// one more than alphabet.len()^2 forces three-character labels for every item
+		let labels = jump_labels(&alphabet, 5);
+		assert_eq!(
+			labels,
+			vec!["aaa", "aab", "aba", "abb", "baa"]
+		);
+	}
+}
+
+// Live state of an in-progress jump prompt: every instance's assigned label plus however much of
+// it the user has typed so far. Lives on `NodeDefRenderer` rather than in `run`'s locals because
+// `Widget::render` needs to see it to dim non-matching labels
+#[derive(Debug)]
+struct JumpState {
+	// instance id -> label, computed once against the full `instance_layout` when the prompt opens
+	labels: HashMap<usize, String>,
+	typed: String,
+}
+
+// The overlap-resolution rule `place_rows` applies to every instance it places: line up with the
+// upstream socket it anchors to when that row is still free, but never move up into whatever's
+// already occupying `cur_y` in this column - only ever push down. Pulled out as a free function so
+// the invariant can be unit tested without a full `NodeDefRenderer`/`Instance` fixture.
+fn resolve_anchored_row(anchor_y: Option<i32>, cur_y: i32) -> i32 {
+	anchor_y.unwrap_or(cur_y).max(cur_y)
+}
+
+#[cfg(test)]
+mod resolve_anchored_row_tests {
+	use super::resolve_anchored_row;
+
+	#[test]
+	fn with_no_anchor_it_packs_at_the_current_row() {
+		assert_eq!(resolve_anchored_row(None, 7), 7);
+	}
+
+	#[test]
+	fn an_anchor_below_the_current_row_is_honored() {
+		assert_eq!(resolve_anchored_row(Some(12), 7), 12);
+	}
+
+	#[test]
+	fn an_anchor_above_the_current_row_is_clamped_down_instead_of_moving_up() {
+		// the instance above it in this column already claimed rows up through `cur_y` - never
+		// move up into that, only ever push down
+		assert_eq!(resolve_anchored_row(Some(2), 7), 7);
+	}
+
+	#[test]
+	fn an_anchor_exactly_at_the_current_row_is_a_no_op() {
+		assert_eq!(resolve_anchored_row(Some(7), 7), 7);
 	}
 }
 
@@ -154,6 +443,11 @@ struct NodeDefRenderer {
 	type_table: Vec<TypeEntry>,
 
 	instance_layout: Vec<Column>,
+	connection_edges: Vec<routing::Edge>,
+	connection_routes: Vec<routing::Route>,
+	jump: Option<JumpState>,
+	// Instance the inspector panel is currently showing detail for
+	selected: Option<usize>,
 	x_shift: i32,
 	y_shift: i32,
 }
@@ -192,17 +486,393 @@ impl NodeDefRenderer {
 			}
 		}
 
-		// Reorganize into columns
-		let mut columns = vec![];
-		columns.resize_with(max_depth + 1, Column::default);
-
+		// Bucket ids by depth, sorting within each bucket by the instance's original stored y so
+		// that, barring alignment, on-screen vertical order matches the source file's order
+		let mut buckets: Vec<Vec<InstanceID>> = vec![];
+		buckets.resize_with(max_depth + 1, Vec::new);
 		for (instance_id, depth) in depths {
-			columns[depth].instances.push(instance_id);
+			buckets[depth].push(instance_id);
+		}
+		for bucket in buckets.iter_mut() {
+			bucket.sort_by_key(|id| self.instance_table.get(id).unwrap().y as i32);
 		}
 
+		let mut columns = self.place_rows(buckets);
+		let edges = self.compute_connection_edges(&columns);
+		self.assign_columns_x(&mut columns, &edges);
+		self.connection_routes = routing::build_routes(&columns, &edges);
+		self.connection_edges = edges;
 		self.instance_layout = columns;
 	}
 
+	// Walks columns from highest depth (leftmost) to depth 0 (rightmost), giving each instance a
+	// `y_pos` that lines its input sockets up with the upstream socket they connect from. Within
+	// a column the stored-y order from `buckets` is never changed - if aligning an instance would
+	// overlap the one placed above it, it (and everything after it) is simply pushed down by
+	// inserting empty padding rows. Because columns are resolved left-to-right and each placed
+	// instance's row is recorded before the next column reads it, the alignment naturally
+	// propagates rightward and single-source chains come out as straight horizontal lines.
+	//
+	// `x_pos` isn't known yet at this point (it depends on how wide the routed connections force
+	// the gaps between columns to be), so every instance is placed at `x_pos = 0` here and
+	// `assign_columns_x` fixes it up afterwards.
+	fn place_rows(&self, buckets: Vec<Vec<InstanceID>>) -> Vec<Column> {
+		// (instance id, output socket index) -> the absolute row that output socket ended up on,
+		// so downstream columns can anchor to the specific socket they connect from rather than
+		// just the box's top row
+		let mut placed_socket_y: HashMap<(usize, usize), i32> = HashMap::new();
+		let mut columns: Vec<Column> = vec![];
+		columns.resize_with(buckets.len(), Column::default);
+
+		for depth in (0..buckets.len()).rev() {
+			let mut cur_y = 0;
+
+			for instance_id in buckets[depth].iter() {
+				let instance = self.instance_table.get(instance_id).unwrap();
+				let mut renderer =
+					InstanceRenderer::from_instance(instance, &self.node_table, &self.type_table, 0, cur_y);
+
+				// Anchor so the first upstream connection that's already been placed lines up
+				// exactly: row of its output socket minus this instance's input socket offset
+				let anchor_y = instance.sockets.iter().enumerate().find_map(|(input_index, socket)| {
+					match socket.data {
+						Some(DataType::Connection(source_id, source_socket)) if source_id != 255 => {
+							placed_socket_y
+								.get(&(source_id as usize, source_socket as usize))
+								.map(|source_row| source_row - renderer.socket_row(input_index))
+						}
+						_ => None,
+					}
+				});
+
+				renderer.y_pos = resolve_anchored_row(anchor_y, cur_y);
+
+				cur_y = renderer.y_pos + renderer.height + PAD_Y;
+				for output_index in 0..renderer.output_labels.len() {
+					placed_socket_y.insert((*renderer.id, output_index), renderer.output_anchor(output_index).1);
+				}
+				columns[depth].instances.push(renderer);
+			}
+		}
+
+		columns
+	}
+
+	// Walks every instance's input sockets and records each one that's bound to a connection, so
+	// the padding pass, the router, and the inspector's edge counts can all work from a single
+	// list instead of re-deriving it
+	fn compute_connection_edges(&self, columns: &[Column]) -> Vec<routing::Edge> {
+		let depth_of: HashMap<usize, usize> = columns
+			.iter()
+			.enumerate()
+			.flat_map(|(depth, column)| column.instances.iter().map(move |r| (*r.id, depth)))
+			.collect();
+		let renderer_of: HashMap<usize, &InstanceRenderer> = columns
+			.iter()
+			.flat_map(|column| column.instances.iter().map(|r| (*r.id, r)))
+			.collect();
+
+		let mut edges = vec![];
+		for instance in self.instance_table.values() {
+			for (input_index, socket) in instance.sockets.iter().enumerate() {
+				let Some(DataType::Connection(source_id, source_socket)) = socket.data else {
+					continue;
+				};
+				if source_id == 255 {
+					continue;
+				}
+				let (source_id, dest_id) = (source_id as usize, instance.key);
+				let (Some(&source_depth), Some(&dest_depth)) =
+					(depth_of.get(&source_id), depth_of.get(&dest_id))
+				else {
+					continue;
+				};
+				edges.push(routing::Edge {
+					source_id,
+					source_socket: source_socket as usize,
+					source_depth,
+					source_row: renderer_of[&source_id].output_anchor(source_socket as usize).1,
+					dest_id,
+					dest_socket: input_index,
+					dest_depth,
+					dest_row: renderer_of[&dest_id].input_anchor(input_index).1,
+				});
+			}
+		}
+
+		// `instance_table` is a `HashMap`, so the order edges were discovered in is randomized
+		// per-process; `allocate_channels` breaks ties among same-row connections by input order,
+		// so without a stable sort here the channel (and therefore column) a connection bends in
+		// would vary between runs of the same program on the same file.
+		edges.sort_by_key(|e| (e.source_id, e.source_socket, e.dest_id, e.dest_socket));
+
+		edges
+	}
+
+	// Assigns `x_pos` column by column, growing each gap to the number of bends
+	// `routing::build_routes` will route through it (at least as many as the channels it allocates
+	// there) instead of the old constant `PAD_X`, so there's
+	// always room for the router to bend every connection that needs a dedicated channel there.
+	//
+	// A gap right of column `depth` carries two independent channel pools, stacking in from
+	// opposite sides of the gap (see `build_routes`'s `exit_x`/`entry_x`): exit bends for edges
+	// leaving column `depth` (every skip edge, since it always detours via the expressway
+	// regardless of row alignment, plus any non-skip edge whose rows don't already line up), and
+	// entry bends for skip edges landing in column `depth - 1` (keyed the same way `build_routes`
+	// keys `entry_requests`, by `dest_depth + 1`). The gap has to fit both pools at once, so its
+	// width is their sum, not their max.
+	fn assign_columns_x(&self, columns: &mut [Column], edges: &[routing::Edge]) {
+		let is_skip = |edge: &routing::Edge| (edge.source_depth as isize - edge.dest_depth as isize).abs() > 1;
+
+		let mut exit_count_of: HashMap<usize, i32> = HashMap::new();
+		let mut entry_count_of: HashMap<usize, i32> = HashMap::new();
+		for edge in edges {
+			if is_skip(edge) {
+				*exit_count_of.entry(edge.source_depth).or_insert(0) += 1;
+				*entry_count_of.entry(edge.dest_depth + 1).or_insert(0) += 1;
+			} else if edge.source_row != edge.dest_row {
+				*exit_count_of.entry(edge.source_depth).or_insert(0) += 1;
+			}
+		}
+
+		let mut cur_x = 20 + PAD_X; // the input root occupies the space to the left of column 0
+
+		for depth in (0..columns.len()).rev() {
+			let max_width = columns[depth]
+				.instances
+				.iter()
+				.map(|r| r.width)
+				.max()
+				.unwrap_or(0);
+
+			for renderer in columns[depth].instances.iter_mut() {
+				renderer.x_pos = cur_x;
+			}
+
+			let gap = if depth == 0 {
+				PAD_X
+			} else {
+				let exit_count = *exit_count_of.get(&depth).unwrap_or(&0);
+				let entry_count = *entry_count_of.get(&depth).unwrap_or(&0);
+				(exit_count + entry_count).max(1)
+			};
+			cur_x += max_width + gap;
+		}
+	}
+
+	// Labels every instance in `instance_layout` - the full graph, not just what's currently
+	// scrolled into view - so a jump prompt can reach anything. Instances are walked in the same
+	// column/row order `render` draws them in: top-to-bottom within a column, starting from the
+	// depth-0 (rightmost) column and moving leftwards.
+	fn assign_jump_labels(&self) -> HashMap<usize, String> {
+		let alphabet: Vec<char> = JUMP_ALPHABET.chars().collect();
+		let ids: Vec<usize> = self
+			.instance_layout
+			.iter()
+			.flat_map(|column| column.instances.iter().map(|renderer| *renderer.id))
+			.collect();
+
+		jump_labels(&alphabet, ids.len())
+			.into_iter()
+			.zip(ids)
+			.map(|(label, id)| (id, label))
+			.collect()
+	}
+
+	fn enter_jump_mode(&mut self) {
+		self.jump = Some(JumpState {
+			labels: self.assign_jump_labels(),
+			typed: String::new(),
+		});
+	}
+
+	// Feeds one typed key into an open jump prompt. Returns the id of the instance to center on
+	// once a label is fully matched, closing the prompt either then or as soon as no label can
+	// match what's been typed anymore
+	fn handle_jump_key(&mut self, code: KeyCode) -> Option<usize> {
+		let jump = self.jump.as_mut()?;
+		match code {
+			KeyCode::Char(c) => {
+				jump.typed.push(c);
+				let completed = jump
+					.labels
+					.iter()
+					.find(|(_, label)| label.as_str() == jump.typed)
+					.map(|(&id, _)| id);
+				let any_match = jump.labels.values().any(|label| label.starts_with(&jump.typed));
+
+				if completed.is_some() || !any_match {
+					self.jump = None;
+				}
+				completed
+			}
+			_ => {
+				self.jump = None;
+				None
+			}
+		}
+	}
+
+	// Shifts the view so `id` lands in the middle of a `viewport_width` x `viewport_height` area
+	fn center_on(&mut self, id: usize, viewport_width: u16, viewport_height: u16) {
+		let Some(target) = self
+			.instance_layout
+			.iter()
+			.flat_map(|column| column.instances.iter())
+			.find(|renderer| *renderer.id == id)
+		else {
+			return;
+		};
+
+		self.x_shift = target.x_pos + target.width / 2 - viewport_width as i32 / 2;
+		self.y_shift = target.y_pos + target.height / 2 - viewport_height as i32 / 2;
+	}
+
+	// (depth, row-within-column) of the selected instance in `instance_layout`, used by
+	// `select_horizontal`/`select_vertical` to move along actual column/row adjacency
+	fn selected_position(&self) -> Option<(usize, usize)> {
+		let id = self.selected?;
+		self.instance_layout.iter().enumerate().find_map(|(depth, column)| {
+			column
+				.instances
+				.iter()
+				.position(|renderer| *renderer.id == id)
+				.map(|row| (depth, row))
+		})
+	}
+
+	// Tab/Shift-Tab cycle through every instance in the same flattened order the rest of the
+	// renderer uses, with no notion of column/row adjacency - just "the next/previous thing"
+	fn select_step(&mut self, delta: isize) {
+		let ids: Vec<usize> = self
+			.instance_layout
+			.iter()
+			.flat_map(|column| column.instances.iter().map(|renderer| *renderer.id))
+			.collect();
+		if ids.is_empty() {
+			return;
+		}
+
+		let current = self.selected.and_then(|id| ids.iter().position(|&i| i == id));
+		let next = match current {
+			Some(i) => (i as isize + delta).rem_euclid(ids.len() as isize) as usize,
+			None if delta >= 0 => 0,
+			None => ids.len() - 1,
+		};
+		self.selected = Some(ids[next]);
+	}
+
+	// h/l: hop to the neighbouring column (`delta` is added to the depth, so a negative delta moves
+	// towards depth 0, i.e. rightward - see the depth-numbering note in `init_layout`), landing on whichever
+	// instance in that column sits closest to the current row
+	fn select_horizontal(&mut self, delta: isize) {
+		let Some((depth, _)) = self.selected_position() else {
+			self.select_step(0.max(delta));
+			return;
+		};
+		let new_depth = depth as isize + delta;
+		if new_depth < 0 || new_depth as usize >= self.instance_layout.len() {
+			return;
+		}
+		let new_depth = new_depth as usize;
+
+		let current_y = self
+			.instance_layout[depth]
+			.instances
+			.iter()
+			.find(|renderer| Some(*renderer.id) == self.selected)
+			.map(|renderer| renderer.y_pos)
+			.unwrap_or(0);
+
+		if let Some(closest) = self.instance_layout[new_depth]
+			.instances
+			.iter()
+			.min_by_key(|renderer| (renderer.y_pos - current_y).abs())
+		{
+			self.selected = Some(*closest.id);
+		}
+	}
+
+	// j/k: move to the next/previous instance within the same column, in the same top-to-bottom
+	// order `place_rows` built it in
+	fn select_vertical(&mut self, delta: isize) {
+		let Some((depth, row)) = self.selected_position() else {
+			self.select_step(0.max(delta));
+			return;
+		};
+
+		let column = &self.instance_layout[depth];
+		let new_row = row as isize + delta;
+		if new_row < 0 || new_row as usize >= column.instances.len() {
+			return;
+		}
+		self.selected = Some(*column.instances[new_row as usize].id);
+	}
+
+	// Resolves a socket's `DataType` into display text: the node name a `Connection` points to,
+	// or the literal value for anything else
+	fn describe_socket_value(&self, data: Option<DataType>) -> String {
+		match data {
+			None => "-".to_string(),
+			Some(DataType::Connection(source_id, _)) if source_id != 255 => {
+				let name = self
+					.instance_table
+					.get(&InstanceID(source_id as usize))
+					.map(|source| self.node_table[source.node as usize].name.as_str())
+					.unwrap_or("?");
+				format!("<- {name}")
+			}
+			Some(DataType::Connection(..)) => "-".to_string(),
+			Some(other) => format!("{other:?}"),
+		}
+	}
+
+	// Renders the detail panel for whatever's selected into the raw terminal buffer directly -
+	// this is viewport-local UI chrome, not part of the scrollable graph, so it bypasses
+	// `OverdrawBuffer` entirely
+	fn render_inspector(&self, area: Rect, buf: &mut Buffer) {
+		let Some(id) = self.selected else { return };
+		let Some(instance) = self.instance_table.get(&InstanceID(id)) else {
+			return;
+		};
+		let node = &self.node_table[instance.node as usize];
+
+		Block::bordered().title("inspector").render(area, buf);
+
+		let incoming = self.connection_edges.iter().filter(|edge| edge.dest_id == id).count();
+		let outgoing = self.connection_edges.iter().filter(|edge| edge.source_id == id).count();
+
+		let mut lines = vec![
+			format!("node: {}", node.name),
+			format!("in: {incoming}  out: {outgoing}"),
+			String::new(),
+			"inputs:".to_string(),
+		];
+		for (i, socket) in instance.sockets.iter().enumerate() {
+			let label = match node.inputs.get(i) {
+				Some(input) => self.type_table[input.type_id as usize].name.as_str(),
+				None => "?",
+			};
+			let value = self.describe_socket_value(socket.data);
+			lines.push(format!(" {i} {label}: {value}"));
+		}
+		lines.push(String::new());
+		lines.push("outputs:".to_string());
+		for (i, output) in node.outputs.iter().enumerate() {
+			let label = &self.type_table[output.type_id as usize].name;
+			lines.push(format!(" {i} {label}"));
+		}
+
+		let inner_width = area.width.saturating_sub(2) as usize;
+		for (row, line) in lines.iter().enumerate() {
+			let y = area.y + 1 + row as u16;
+			if y >= area.y + area.height.saturating_sub(1) {
+				break;
+			}
+			buf.set_stringn(area.x + 1, y, line, inner_width, Style::new());
+		}
+	}
+
 	fn from_node(node: Node) -> Self {
 		match node {
 			Node::V1(node_def) => {
@@ -221,6 +891,10 @@ impl NodeDefRenderer {
 					node_table,
 					type_table,
 					instance_layout: vec![],
+					connection_edges: vec![],
+					connection_routes: vec![],
+					jump: None,
+					selected: None,
 					x_shift: 0,
 					y_shift: 0,
 				};
@@ -233,59 +907,459 @@ impl NodeDefRenderer {
 		}
 	}
 }
+
+// Draws the orthogonal wires between sockets: horizontal run out of the source, a bend in a
+// dedicated vertical channel, then a horizontal run into the destination. See the big comment at
+// the top of the file for the general approach and why we don't try to be clever about overlaps.
+mod routing {
+	use std::collections::HashMap;
+
+	use super::{Column, OverdrawBuffer, Style};
+
+	// One socket-to-socket connection. Only the socket rows are stored here - `x_pos` isn't finalized
+	// until after edges are built, so endpoints are looked up from the final `InstanceRenderer`s
+	// in `build_routes` instead.
+	#[derive(Debug, Clone, Copy)]
+	pub(super) struct Edge {
+		pub(super) source_id: usize,
+		pub(super) source_socket: usize,
+		pub(super) source_depth: usize,
+		pub(super) source_row: i32,
+		pub(super) dest_id: usize,
+		pub(super) dest_socket: usize,
+		pub(super) dest_depth: usize,
+		pub(super) dest_row: i32,
+	}
+
+	// An orthogonal polyline in global layout coordinates: two points (a straight run, when
+	// source and destination already share a row), four (exit, bend, bend, entry), or six for a
+	// skip edge (exit, up to the expressway, along it, down to the destination row, entry)
+	pub(super) struct Route {
+		points: Vec<(i32, i32)>,
+	}
+
+	// Greedily assigns each request a distinct channel index within its own bucket, by sorting the
+	// bucket's requests by row and packing them into the first channel whose previous occupant's
+	// row range doesn't overlap the new one. Shared by both the exit-side and entry-side bend
+	// allocations in `build_routes` - they're the same packing problem, just in different gaps.
+	fn allocate_channels<K: Eq + std::hash::Hash + Copy>(requests: &[(K, i32, i32)]) -> HashMap<K, i32> {
+		let mut sorted = requests.to_vec();
+		sorted.sort_by_key(|&(_, top, _)| top);
+
+		// channel index -> the row range it's currently occupied by
+		let mut occupied: Vec<(i32, i32)> = vec![];
+		let mut channel_of = HashMap::new();
+		for (key, top, bottom) in sorted {
+			let free_channel = occupied.iter().position(|&(_, prev_bottom)| prev_bottom < top);
+			let channel = match free_channel {
+				Some(i) => {
+					occupied[i] = (top, bottom);
+					i
+				}
+				None => {
+					occupied.push((top, bottom));
+					occupied.len() - 1
+				}
+			};
+			channel_of.insert(key, channel as i32);
+		}
+
+		channel_of
+	}
+
+	// Builds one `Route` per edge. An edge whose destination sits in the column immediately right
+	// of its source gets at most one bend, in the gap between them, same as a straight connection
+	// but offset into its own channel. An edge that skips past one or more intermediate columns
+	// instead bends out to a shared "expressway" row - a single row above every instance in the
+	// whole layout, so it's guaranteed free of box content - runs along it, and bends back down
+	// into its destination, so its horizontal run never cuts through an intervening column's boxes.
+	pub(super) fn build_routes(columns: &[Column], edges: &[Edge]) -> Vec<Route> {
+		let renderer_of: HashMap<usize, &super::InstanceRenderer> = columns
+			.iter()
+			.flat_map(|column| column.instances.iter().map(|r| (*r.id, r)))
+			.collect();
+
+		let expressway_y = columns
+			.iter()
+			.flat_map(|column| column.instances.iter().map(|r| r.y_pos))
+			.min()
+			.unwrap_or(0)
+			- 1;
+
+		let endpoints: Vec<((i32, i32), (i32, i32))> = edges
+			.iter()
+			.map(|edge| {
+				let from = renderer_of[&edge.source_id].output_anchor(edge.source_socket);
+				let to = renderer_of[&edge.dest_id].input_anchor(edge.dest_socket);
+				(from, to)
+			})
+			.collect();
+		let is_skip = |edge: &Edge| (edge.source_depth as isize - edge.dest_depth as isize).abs() > 1;
+
+		// Exit bends live in the gap immediately right of their source column (keyed by
+		// `source_depth`, same as a non-skip bend); entry bends live in the gap immediately left
+		// of their destination column (keyed by `dest_depth + 1`). Requests are keyed by edge
+		// index rather than `(source_id, source_socket)`/`(dest_id, dest_socket)` - a socket that
+		// fans out to several destinations produces several requests sharing the same socket, and
+		// they still need distinct channels from each other.
+		let mut exit_requests: HashMap<usize, Vec<(usize, i32, i32)>> = HashMap::new();
+		let mut entry_requests: HashMap<usize, Vec<(usize, i32, i32)>> = HashMap::new();
+		for (i, edge) in edges.iter().enumerate() {
+			let (from, to) = endpoints[i];
+			if is_skip(edge) {
+				let (top, bottom) = (from.1.min(expressway_y), from.1.max(expressway_y));
+				exit_requests.entry(edge.source_depth).or_default().push((i, top, bottom));
+
+				let (top, bottom) = (expressway_y.min(to.1), expressway_y.max(to.1));
+				entry_requests.entry(edge.dest_depth + 1).or_default().push((i, top, bottom));
+			} else if from.1 != to.1 {
+				let (top, bottom) = (from.1.min(to.1), from.1.max(to.1));
+				exit_requests.entry(edge.source_depth).or_default().push((i, top, bottom));
+			}
+		}
+
+		// edge index -> the x its exit bend was assigned, stacking channels rightward away from
+		// the source column
+		let mut exit_x: HashMap<usize, i32> = HashMap::new();
+		for (&depth, requests) in exit_requests.iter() {
+			let gap_left_x = columns[depth]
+				.instances
+				.iter()
+				.map(|r| r.x_pos + r.width)
+				.max()
+				.unwrap_or(0);
+			for (key, channel) in allocate_channels(requests) {
+				exit_x.insert(key, gap_left_x + channel);
+			}
+		}
+
+		// edge index -> the x its entry bend was assigned, stacking channels leftward away from
+		// the destination column
+		let mut entry_x: HashMap<usize, i32> = HashMap::new();
+		for (&depth, requests) in entry_requests.iter() {
+			// keyed by `dest_depth + 1`, so the destination column itself is `depth - 1`
+			let gap_right_x = columns[depth - 1].instances.iter().map(|r| r.x_pos).min().unwrap_or(0);
+			for (key, channel) in allocate_channels(requests) {
+				entry_x.insert(key, gap_right_x - 1 - channel);
+			}
+		}
+
+		edges
+			.iter()
+			.enumerate()
+			.map(|(i, edge)| {
+				let (from, to) = endpoints[i];
+
+				if is_skip(edge) {
+					let exit = exit_x[&i];
+					let entry = entry_x[&i];
+					Route {
+						points: vec![
+							from,
+							(exit, from.1),
+							(exit, expressway_y),
+							(entry, expressway_y),
+							(entry, to.1),
+							to,
+						],
+					}
+				} else if from.1 == to.1 {
+					Route {
+						points: vec![from, to],
+					}
+				} else {
+					let bend_x = exit_x[&i];
+					Route {
+						points: vec![from, (bend_x, from.1), (bend_x, to.1), to],
+					}
+				}
+			})
+			.collect()
+	}
+
+	// Rasterizes every route into `buf`. Two routes sharing a cell are merged into '┼', and a
+	// route that runs off the edge of `buf`'s visible area is cut short with an arrow glyph rather
+	// than just disappearing mid-line.
+	pub(super) fn draw_routes(buf: &mut OverdrawBuffer, routes: &[Route], style: Style) {
+		let mut glyphs: HashMap<(i32, i32), char> = HashMap::new();
+		for route in routes {
+			draw_route(route, &mut glyphs);
+		}
+		for route in routes {
+			apply_clip_arrows(buf, route, &mut glyphs);
+		}
+
+		let mut char_buf = [0u8; 4];
+		for (&(x, y), &glyph) in glyphs.iter() {
+			buf.set_stringn(x, y, glyph.encode_utf8(&mut char_buf), 1, style);
+		}
+	}
+
+	fn draw_route(route: &Route, glyphs: &mut HashMap<(i32, i32), char>) {
+		// Drawn into its own map first so the route's own joints become corners rather than being
+		// merged into '┼'; only cells shared with other routes are merged
+		let mut own: HashMap<(i32, i32), char> = HashMap::new();
+		for segment in route.points.windows(2) {
+			draw_straight_segment(segment[0], segment[1], &mut own);
+		}
+		for corner in route.points.windows(3) {
+			if let Some(glyph) = corner_glyph(corner[0], corner[1], corner[2]) {
+				own.insert(corner[1], glyph);
+			}
+		}
+		for (pos, glyph) in own {
+			set_glyph(glyphs, pos, glyph);
+		}
+	}
+
+	fn draw_straight_segment(from: (i32, i32), to: (i32, i32), glyphs: &mut HashMap<(i32, i32), char>) {
+		if from.1 == to.1 {
+			for x in from.0.min(to.0)..=from.0.max(to.0) {
+				set_glyph(glyphs, (x, from.1), '─');
+			}
+		} else {
+			for y in from.1.min(to.1)..=from.1.max(to.1) {
+				set_glyph(glyphs, (from.0, y), '│');
+			}
+		}
+	}
+
+	// Our routes only ever turn horizontal-to-vertical or vertical-to-horizontal, so there are
+	// only four possible corners
+	fn corner_glyph(prev: (i32, i32), corner: (i32, i32), next: (i32, i32)) -> Option<char> {
+		let from_left = prev.1 == corner.1 && prev.0 < corner.0;
+		let from_above = prev.0 == corner.0 && prev.1 < corner.1;
+		let from_below = prev.0 == corner.0 && prev.1 > corner.1;
+		let to_down = next.0 == corner.0 && next.1 > corner.1;
+		let to_up = next.0 == corner.0 && next.1 < corner.1;
+		let to_right = next.1 == corner.1 && next.0 > corner.0;
+
+		if from_left && to_down {
+			Some('┐')
+		} else if from_left && to_up {
+			Some('┘')
+		} else if from_above && to_right {
+			Some('└')
+		} else if from_below && to_right {
+			Some('┌')
+		} else {
+			None
+		}
+	}
+
+	fn set_glyph(glyphs: &mut HashMap<(i32, i32), char>, pos: (i32, i32), glyph: char) {
+		glyphs
+			.entry(pos)
+			.and_modify(|existing| {
+				if *existing != glyph {
+					*existing = '┼';
+				}
+			})
+			.or_insert(glyph);
+	}
+
+	// Walks each straight segment cell by cell and, wherever it crosses into or out of `buf`'s
+	// visible area, swaps the last cell still on the visible side for a directional arrow
+	fn apply_clip_arrows(buf: &OverdrawBuffer, route: &Route, glyphs: &mut HashMap<(i32, i32), char>) {
+		for segment in route.points.windows(2) {
+			let (from, to) = (segment[0], segment[1]);
+			let step = ((to.0 - from.0).signum(), (to.1 - from.1).signum());
+			let len = (to.0 - from.0).abs().max((to.1 - from.1).abs());
+
+			let mut prev_visible = buf.to_local(from.0, from.1).is_some();
+			for i in 1..=len {
+				let pos = (from.0 + step.0 * i, from.1 + step.1 * i);
+				let visible = buf.to_local(pos.0, pos.1).is_some();
+
+				if visible != prev_visible {
+					let (arrow_pos, direction) = if prev_visible {
+						((pos.0 - step.0, pos.1 - step.1), step)
+					} else {
+						(pos, (-step.0, -step.1))
+					};
+					glyphs.insert(arrow_pos, arrow_glyph(direction));
+				}
+
+				prev_visible = visible;
+			}
+		}
+	}
+
+	fn arrow_glyph(direction: (i32, i32)) -> char {
+		match direction {
+			(1, 0) => '>',
+			(-1, 0) => '<',
+			(0, 1) => 'v',
+			(0, -1) => '^',
+			_ => '?',
+		}
+	}
+
+	#[cfg(test)]
+	mod allocate_channels_tests {
+		use super::allocate_channels;
+
+		#[test]
+		fn non_overlapping_requests_share_a_single_channel() {
+			let requests = [(0, 0, 2), (1, 3, 5), (2, 6, 8)];
+			let channels = allocate_channels(&requests);
+			assert_eq!(channels[&0], 0);
+			assert_eq!(channels[&1], 0);
+			assert_eq!(channels[&2], 0);
+		}
+
+		#[test]
+		fn overlapping_requests_get_distinct_channels() {
+			let requests = [(0, 0, 5), (1, 2, 7)];
+			let channels = allocate_channels(&requests);
+			assert_ne!(channels[&0], channels[&1]);
+		}
+
+		#[test]
+		fn a_channel_is_reused_once_its_occupant_ends_before_the_next_one_starts() {
+			// 0 occupies rows 0..=2, 1 overlaps it so needs a second channel, 2 starts after 0
+			// frees up and should reuse channel 0 rather than opening a third
+			let requests = [(0, 0, 2), (1, 1, 4), (2, 3, 6)];
+			let channels = allocate_channels(&requests);
+			assert_eq!(channels[&0], 0);
+			assert_eq!(channels[&1], 1);
+			assert_eq!(channels[&2], 0);
+		}
+
+		#[test]
+		fn channel_assignment_does_not_depend_on_input_order_once_rows_are_sorted() {
+			let requests = [(0, 6, 8), (1, 0, 2), (2, 3, 5)];
+			let channels = allocate_channels(&requests);
+			assert_eq!(channels[&0], 0);
+			assert_eq!(channels[&1], 0);
+			assert_eq!(channels[&2], 0);
+		}
+
+		#[test]
+		fn a_request_starting_exactly_where_the_previous_one_ends_still_counts_as_overlapping() {
+			// reuse requires `prev_bottom < top` strictly, so a shared boundary row still forces
+			// a distinct channel
+			let requests = [(0, 0, 3), (1, 3, 6)];
+			let channels = allocate_channels(&requests);
+			assert_ne!(channels[&0], channels[&1]);
+		}
+
+		#[test]
+		fn fan_out_edges_keyed_per_edge_each_keep_their_own_channel() {
+			// a socket fanning out to two destinations - one above the source row, one below -
+			// produces two requests whose ranges touch at the shared source row, so they need
+			// distinct channels from each other. `allocate_channels` can only hold one channel per
+			// key, so callers must key fanned-out requests per-edge (e.g. edge index) rather than
+			// by the shared (source_id, source_socket) - keying both requests the same here would
+			// let the second silently overwrite the first's assignment.
+			let requests = [(0, 0, 5), (1, 5, 10)];
+			let channels = allocate_channels(&requests);
+			assert_eq!(channels.len(), 2, "each fanned-out edge must keep its own channel assignment");
+			assert_ne!(channels[&0], channels[&1]);
+		}
+	}
+}
+
+// Width of the inspector side panel, reserved out of the right edge of the draw area only while
+// something is selected
+const PANEL_WIDTH: u16 = 28;
+
 impl Widget for &NodeDefRenderer {
 	fn render(self, area: Rect, buffer: &mut Buffer) {
-		// Render input root, then columns from last to first, then output root
-		let mut cur_x = 0;
-		let mut cur_y = 0;
+		let panel_width = if self.selected.is_some() {
+			PANEL_WIDTH.min(area.width / 2)
+		} else {
+			0
+		};
+		let graph_area = Rect::new(area.x, area.y, area.width - panel_width, area.height);
 
-		// temp paddings
-		let pad_x = 3;
-		let pad_y = 1;
+		let mut buf = OverdrawBuffer::new(&mut *buffer, graph_area, self.x_shift, self.y_shift);
 
 		// TODO: Render a proper input root
-		buffer.set_style(
-			Rect::new(cur_x as u16, cur_y as u16, 20, 5),
-			Style::new().bg(Color::Rgb(100, 100, 100)),
-		);
-
-		cur_x += 20 + pad_x;
+		buf.set_style(0, 0, 20, 5, Style::new().bg(Color::Rgb(100, 100, 100)));
 
-		for column in self.instance_layout.iter().rev() {
-			cur_y = 0;
-			let mut max_width = 0;
-
-			for instance_id in column.instances.iter() {
-				let cur_instance = self.instance_table.get(instance_id).unwrap();
-				let renderer = InstanceRenderer::from_instance(cur_instance, cur_x, cur_y);
-				let cur_width = renderer.width;
-				let cur_height = renderer.height;
+		// Positions were already finalized by `assign_columns_x`, so rendering is just a walk
+		for column in self.instance_layout.iter() {
+			for renderer in column.instances.iter() {
 				let draw_area = Rect::new(
-					cur_x as u16,
-					cur_y as u16,
-					cur_width as u16,
-					cur_height as u16,
+					renderer.x_pos as u16,
+					renderer.y_pos as u16,
+					renderer.width as u16,
+					renderer.height as u16,
 				);
-				renderer.render(draw_area, buffer);
+				let instance_box = InstanceBox {
+					renderer,
+					selected: self.selected == Some(*renderer.id),
+				};
+				// `&instance_box` so we go through `OverdrawWidget`'s blanket impl (keyed on the
+				// `Widget for InstanceBox` impl) rather than `Widget::render` directly
+				OverdrawWidget::render(&instance_box, draw_area, self.x_shift, self.y_shift, &mut buf);
+			}
+		}
 
-				cur_y += cur_height + pad_y;
-				max_width = max_width.max(cur_width);
+		routing::draw_routes(&mut buf, &self.connection_routes, Style::new());
+
+		// Overlay jump labels last so they sit on top of everything else. Labels still matching
+		// what's been typed so far are highlighted; everything else is dimmed out of the way
+		if let Some(jump) = &self.jump {
+			for column in self.instance_layout.iter() {
+				for renderer in column.instances.iter() {
+					let Some(label) = jump.labels.get(&*renderer.id) else {
+						continue;
+					};
+					let style = if label.starts_with(&jump.typed) {
+						Style::new().fg(Color::Black).bg(Color::Yellow)
+					} else {
+						Style::new().fg(Color::DarkGray)
+					};
+					buf.set_stringn(renderer.x_pos, renderer.y_pos, label, label.chars().count(), style);
+				}
 			}
+		}
 
-			cur_x += max_width + pad_x;
+		if panel_width > 0 {
+			let panel_area = Rect::new(area.x + graph_area.width, area.y, panel_width, area.height);
+			self.render_inspector(panel_area, buffer);
 		}
 	}
 }
 
+// How many cells a single arrow-key press pans the view by
+const SCROLL_STEP: i32 = 2;
+
 fn run(mut terminal: DefaultTerminal, mut renderer: NodeDefRenderer) -> std::io::Result<()> {
+	let mut viewport = Rect::default();
 	loop {
-		terminal.draw(|frame| frame.render_widget(&renderer, frame.area()))?;
+		terminal.draw(|frame| {
+			viewport = frame.area();
+			frame.render_widget(&renderer, viewport);
+		})?;
 		if let Event::Key(ke) = event::read()? {
 			if ke.kind != KeyEventKind::Press {
 				continue;
 			}
+
+			if renderer.jump.is_some() {
+				if let Some(target) = renderer.handle_jump_key(ke.code) {
+					renderer.center_on(target, viewport.width, viewport.height);
+				}
+				continue;
+			}
+
 			match ke.code {
 				KeyCode::Char('q') => break,
+				KeyCode::Left => renderer.x_shift -= SCROLL_STEP,
+				KeyCode::Right => renderer.x_shift += SCROLL_STEP,
+				KeyCode::Up => renderer.y_shift -= SCROLL_STEP,
+				KeyCode::Down => renderer.y_shift += SCROLL_STEP,
+				KeyCode::Char('f') => renderer.enter_jump_mode(),
+				KeyCode::Tab => renderer.select_step(1),
+				KeyCode::BackTab => renderer.select_step(-1),
+				KeyCode::Char('h') => renderer.select_horizontal(1),
+				KeyCode::Char('l') => renderer.select_horizontal(-1),
+				KeyCode::Char('j') => renderer.select_vertical(1),
+				KeyCode::Char('k') => renderer.select_vertical(-1),
+				KeyCode::Esc => renderer.selected = None,
 				_ => (),
 			}
 		}